@@ -0,0 +1,48 @@
+use std::marker::PhantomData;
+
+use ffi::*;
+
+/// An animation within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so an `Animation`
+/// can never outlive the `Scene` it was read from, which is what frees the underlying memory on
+/// `Drop`.
+pub struct Animation<'scene>(*mut AiAnimation, PhantomData<&'scene AiScene>);
+
+impl<'scene> Animation<'scene> {
+    /// Returns this animation's name (often empty).
+    pub fn name(&self) -> String {
+        self.name.as_str().to_owned()
+    }
+
+    /// Returns the duration of this animation, in ticks.
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// Returns the number of ticks this animation plays per second.
+    pub fn ticks_per_second(&self) -> f64 {
+        self.ticks_per_second
+    }
+
+    /// Returns the number of per-node animation channels (`mChannels`) this animation has.
+    pub fn num_channels(&self) -> u32 {
+        self.num_channels
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiAnimation;
+
+    impl<'scene> Deref for super::Animation<'scene> {
+        type Target = AiAnimation;
+        fn deref<'a>(&'a self) -> &'a AiAnimation { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait AnimationInternal<'scene> {
+    fn new(raw_animation: *mut AiAnimation) -> Animation<'scene> { Animation(raw_animation, PhantomData) }
+}
+
+impl<'scene> AnimationInternal<'scene> for Animation<'scene> {}
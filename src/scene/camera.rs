@@ -0,0 +1,67 @@
+use std::marker::PhantomData;
+
+use ffi::*;
+
+/// A camera within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so a `Camera` can never
+/// outlive the `Scene` it was read from, which is what frees the underlying memory on `Drop`.
+pub struct Camera<'scene>(*mut AiCamera, PhantomData<&'scene AiScene>);
+
+impl<'scene> Camera<'scene> {
+    /// Returns this camera's name, which matches the `Node` it's attached to.
+    pub fn name(&self) -> String {
+        self.name.as_str().to_owned()
+    }
+
+    /// Returns the camera's position in local (node) space.
+    pub fn position(&self) -> AiVector3D {
+        self.position
+    }
+
+    /// Returns the camera's up vector in local (node) space.
+    pub fn up(&self) -> AiVector3D {
+        self.up
+    }
+
+    /// Returns the point the camera looks at, in local (node) space.
+    pub fn look_at(&self) -> AiVector3D {
+        self.look_at
+    }
+
+    /// Returns the horizontal field of view, in radians.
+    pub fn horizontal_fov(&self) -> f32 {
+        self.horizontal_fov
+    }
+
+    /// Returns the distance to the near clipping plane.
+    pub fn clip_plane_near(&self) -> f32 {
+        self.clip_plane_near
+    }
+
+    /// Returns the distance to the far clipping plane.
+    pub fn clip_plane_far(&self) -> f32 {
+        self.clip_plane_far
+    }
+
+    /// Returns the screen aspect ratio (width over height) this camera was authored for.
+    pub fn aspect(&self) -> f32 {
+        self.aspect
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiCamera;
+
+    impl<'scene> Deref for super::Camera<'scene> {
+        type Target = AiCamera;
+        fn deref<'a>(&'a self) -> &'a AiCamera { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait CameraInternal<'scene> {
+    fn new(raw_camera: *mut AiCamera) -> Camera<'scene> { Camera(raw_camera, PhantomData) }
+}
+
+impl<'scene> CameraInternal<'scene> for Camera<'scene> {}
@@ -0,0 +1,144 @@
+use std::error::Error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::ops::Deref;
+use std::os::raw::c_char;
+use std::slice::from_raw_parts;
+
+use ffi::*;
+
+/// Describes one of the file formats the linked Assimp build can export to,
+/// as reported by `aiGetExportFormatDescription`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportFormatDesc {
+    /// Short format id to pass to `SceneMut::export`/`export_to_blob` (e.g. `"obj"`, `"gltf2"`).
+    pub id: String,
+    /// Human readable description of the format.
+    pub description: String,
+    /// The file extension the format is usually saved under, without the leading dot.
+    pub file_extension: String,
+}
+
+/// Returns the number of export formats the linked Assimp build supports.
+pub fn export_format_count() -> usize {
+    unsafe { aiGetExportFormatCount() as usize }
+}
+
+/// Returns the full list of export formats the linked Assimp build supports.
+pub fn export_formats() -> Vec<ExportFormatDesc> {
+    (0..export_format_count())
+        .map(|id| unsafe {
+            let desc = &*aiGetExportFormatDescription(id);
+            ExportFormatDesc {
+                id: c_str_to_string(desc.id),
+                description: c_str_to_string(desc.description),
+                file_extension: c_str_to_string(desc.file_extension),
+            }
+        })
+        .collect()
+}
+
+unsafe fn c_str_to_string(ptr: *const c_char) -> String {
+    CStr::from_ptr(ptr).to_string_lossy().into_owned()
+}
+
+/// An in-memory result of exporting a scene, as produced by `SceneMut::export_to_blob`.
+///
+/// Deref's to the raw serialized bytes; use `format_extension` to find out what was written.
+pub struct Blob(*const AiExportDataBlob);
+
+impl Blob {
+    /// The file extension Assimp would have used had this blob been written to disk.
+    pub fn format_extension(&self) -> String {
+        unsafe { (*self.0).name.as_str().to_owned() }
+    }
+}
+
+impl Deref for Blob {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        unsafe {
+            let blob = &*self.0;
+            from_raw_parts(blob.data as *const u8, blob.size as usize)
+        }
+    }
+}
+
+impl Drop for Blob {
+    fn drop(&mut self) {
+        unsafe { aiReleaseExportBlob(self.0); }
+    }
+}
+
+/// An error returned by `SceneMut::export`/`export_to_blob` when Assimp fails to export a scene.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportError(String);
+
+impl fmt::Display for ExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to export scene: {}", self.0)
+    }
+}
+
+impl Error for ExportError {
+    fn description(&self) -> &str { &self.0 }
+}
+
+#[doc(hidden)]
+pub trait ExportInternal {
+    fn new(raw_blob: *const AiExportDataBlob) -> Blob { Blob(raw_blob) }
+}
+
+impl ExportInternal for Blob {}
+
+pub fn last_error_to_export_error() -> ExportError {
+    unsafe { ExportError(c_str_to_string(aiGetErrorString())) }
+}
+
+pub fn to_cstring(s: &str) -> CString {
+    CString::new(s).expect("string must not contain a NUL byte")
+}
+
+/// Exporter-specific settings passed to `aiExportSceneEx` via `SceneMut::export_with_properties`.
+/// Which keys a given exporter understands is documented per format by Assimp itself (e.g.
+/// glTF2's `"TEXT_PRETTY_PRINT"` / `"FORCE_32BIT_VERSION"`).
+pub struct ExportProperties(*mut AiExportProperties);
+
+impl ExportProperties {
+    /// Creates an empty property store.
+    pub fn new() -> ExportProperties {
+        ExportProperties(unsafe { aiCreateExportPropertyStore() })
+    }
+
+    /// Sets an integer-valued property.
+    pub fn set_integer(&mut self, name: &str, value: i32) {
+        let name = to_cstring(name);
+        unsafe { aiExportPropertySetInteger(self.0, name.as_ptr(), value); }
+    }
+
+    /// Sets a float-valued property.
+    pub fn set_float(&mut self, name: &str, value: f32) {
+        let name = to_cstring(name);
+        unsafe { aiExportPropertySetFloat(self.0, name.as_ptr(), value); }
+    }
+
+    /// Sets a boolean-valued property.
+    pub fn set_bool(&mut self, name: &str, value: bool) {
+        self.set_integer(name, value as i32);
+    }
+}
+
+impl Drop for ExportProperties {
+    fn drop(&mut self) {
+        unsafe { aiReleaseExportPropertyStore(self.0); }
+    }
+}
+
+#[doc(hidden)]
+pub trait ExportPropertiesInternal {
+    fn get_raw_ptr(&self) -> *const AiExportProperties;
+}
+
+impl ExportPropertiesInternal for ExportProperties {
+    fn get_raw_ptr(&self) -> *const AiExportProperties { self.0 }
+}
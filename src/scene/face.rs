@@ -0,0 +1,40 @@
+use std::marker::PhantomData;
+use std::slice::from_raw_parts;
+
+use ffi::*;
+
+/// A face of a `Mesh` within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so a `Face`
+/// can never outlive the `Scene` it was read from, which is what frees the underlying memory on
+/// `Drop`.
+pub struct Face<'scene>(*const AiFace, PhantomData<&'scene AiScene>);
+
+impl<'scene> Face<'scene> {
+    /// Returns the number of vertex indices making up this face.
+    pub fn num_indices(&self) -> u32 {
+        self.num_indices
+    }
+
+    /// Returns the vertex indices making up this face, into the owning `Mesh`'s `vertices`.
+    pub fn indices(&self) -> &'scene [u32] {
+        let len = self.num_indices as usize;
+        unsafe { from_raw_parts(self.indices, len) }
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiFace;
+
+    impl<'scene> Deref for super::Face<'scene> {
+        type Target = AiFace;
+        fn deref<'a>(&'a self) -> &'a AiFace { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait FaceInternal<'scene> {
+    fn new(raw_face: *const AiFace) -> Face<'scene> { Face(raw_face, PhantomData) }
+}
+
+impl<'scene> FaceInternal<'scene> for Face<'scene> {}
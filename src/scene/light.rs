@@ -0,0 +1,92 @@
+use std::marker::PhantomData;
+
+use ffi::*;
+
+/// A light within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so a `Light` can never
+/// outlive the `Scene` it was read from, which is what frees the underlying memory on `Drop`.
+pub struct Light<'scene>(*mut AiLight, PhantomData<&'scene AiScene>);
+
+impl<'scene> Light<'scene> {
+    /// Returns this light's name, which matches the `Node` it's attached to.
+    pub fn name(&self) -> String {
+        self.name.as_str().to_owned()
+    }
+
+    /// Returns the kind of light this is (point, directional, spot, ...).
+    pub fn light_type(&self) -> AiLightSourceType {
+        self.light_type
+    }
+
+    /// Returns the light's position in local (node) space.
+    pub fn position(&self) -> AiVector3D {
+        self.position
+    }
+
+    /// Returns the light's direction in local (node) space. Undefined for point lights.
+    pub fn direction(&self) -> AiVector3D {
+        self.direction
+    }
+
+    /// Returns the light's up vector in local (node) space. Undefined for point lights.
+    pub fn up(&self) -> AiVector3D {
+        self.up
+    }
+
+    /// Returns the constant term of the light's attenuation formula.
+    pub fn attenuation_constant(&self) -> f32 {
+        self.attenuation_constant
+    }
+
+    /// Returns the linear term of the light's attenuation formula.
+    pub fn attenuation_linear(&self) -> f32 {
+        self.attenuation_linear
+    }
+
+    /// Returns the quadratic term of the light's attenuation formula.
+    pub fn attenuation_quadratic(&self) -> f32 {
+        self.attenuation_quadratic
+    }
+
+    /// Returns the diffuse color this light emits.
+    pub fn color_diffuse(&self) -> AiColor3D {
+        self.color_diffuse
+    }
+
+    /// Returns the specular color this light emits.
+    pub fn color_specular(&self) -> AiColor3D {
+        self.color_specular
+    }
+
+    /// Returns the ambient color this light emits.
+    pub fn color_ambient(&self) -> AiColor3D {
+        self.color_ambient
+    }
+
+    /// Returns the inner cone angle, in radians, for a spot light.
+    pub fn angle_inner_cone(&self) -> f32 {
+        self.angle_inner_cone
+    }
+
+    /// Returns the outer cone angle, in radians, for a spot light.
+    pub fn angle_outer_cone(&self) -> f32 {
+        self.angle_outer_cone
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiLight;
+
+    impl<'scene> Deref for super::Light<'scene> {
+        type Target = AiLight;
+        fn deref<'a>(&'a self) -> &'a AiLight { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait LightInternal<'scene> {
+    fn new(raw_light: *mut AiLight) -> Light<'scene> { Light(raw_light, PhantomData) }
+}
+
+impl<'scene> LightInternal<'scene> for Light<'scene> {}
@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+use std::slice::from_raw_parts;
+
+use ffi::*;
+
+/// A single decoded value from an `aiMaterialProperty`, tagged by its `aiPropertyTypeInfo`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MaterialPropertyValue {
+    Float(f32),
+    Double(f64),
+    String(String),
+    Integer(i32),
+    Buffer(Vec<u8>),
+}
+
+/// A material within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so a `Material` can
+/// never outlive the `Scene` it was read from, which is what frees the underlying memory on
+/// `Drop`.
+pub struct Material<'scene>(*mut AiMaterial, PhantomData<&'scene AiScene>);
+
+impl<'scene> Material<'scene> {
+    /// Returns the number of key/value properties on this material.
+    pub fn num_properties(&self) -> u32 {
+        self.num_properties
+    }
+
+    /// Returns every key/value property on this material, decoded according to its
+    /// `aiPropertyTypeInfo` tag.
+    pub fn properties(&self) -> Vec<(String, MaterialPropertyValue)> {
+        let len = self.num_properties as usize;
+        unsafe {
+            from_raw_parts(self.properties, len)
+                .iter()
+                .map(|&prop| {
+                    let prop = &*prop;
+                    (prop.key.as_str().to_owned(), decode_property(prop))
+                })
+                .collect()
+        }
+    }
+
+    /// Looks up a single property by key. Linear in the number of properties.
+    pub fn get_property(&self, key: &str) -> Option<MaterialPropertyValue> {
+        self.properties().into_iter().find(|&(ref k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+unsafe fn decode_property(prop: &AiMaterialProperty) -> MaterialPropertyValue {
+    match prop.type_info {
+        AiPropertyTypeInfo::Float => MaterialPropertyValue::Float(*(prop.data as *const f32)),
+        AiPropertyTypeInfo::Double => MaterialPropertyValue::Double(*(prop.data as *const f64)),
+        AiPropertyTypeInfo::String => {
+            MaterialPropertyValue::String((*(prop.data as *const AiString)).as_str().to_owned())
+        }
+        AiPropertyTypeInfo::Integer => MaterialPropertyValue::Integer(*(prop.data as *const i32)),
+        AiPropertyTypeInfo::Buffer => {
+            let bytes = from_raw_parts(prop.data as *const u8, prop.data_length as usize);
+            MaterialPropertyValue::Buffer(bytes.to_vec())
+        }
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiMaterial;
+
+    impl<'scene> Deref for super::Material<'scene> {
+        type Target = AiMaterial;
+        fn deref<'a>(&'a self) -> &'a AiMaterial { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait MaterialInternal<'scene> {
+    fn new(raw_material: *mut AiMaterial) -> Material<'scene> { Material(raw_material, PhantomData) }
+}
+
+impl<'scene> MaterialInternal<'scene> for Material<'scene> {}
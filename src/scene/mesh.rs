@@ -0,0 +1,78 @@
+use std::marker::PhantomData;
+use std::slice::from_raw_parts;
+
+use ffi::*;
+
+use super::face::{Face, FaceInternal};
+
+/// A mesh within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so a `Mesh` can never
+/// outlive the `Scene` it was read from, which is what frees the underlying memory on `Drop`.
+pub struct Mesh<'scene>(*mut AiMesh, PhantomData<&'scene AiScene>);
+
+impl<'scene> Mesh<'scene> {
+    /// Returns this mesh's name, as set by the importer (often empty).
+    pub fn name(&self) -> String {
+        self.name.as_str().to_owned()
+    }
+
+    /// Returns the number of vertices in this mesh.
+    pub fn num_vertices(&self) -> u32 {
+        self.num_vertices
+    }
+
+    /// Returns this mesh's vertex positions.
+    pub fn vertices(&self) -> &'scene [AiVector3D] {
+        let len = self.num_vertices as usize;
+        unsafe { from_raw_parts(self.vertices, len) }
+    }
+
+    /// Returns this mesh's per-vertex normals, or `None` if the importer didn't generate any.
+    pub fn normals(&self) -> Option<&'scene [AiVector3D]> {
+        if self.normals.is_null() {
+            None
+        } else {
+            let len = self.num_vertices as usize;
+            Some(unsafe { from_raw_parts(self.normals, len) })
+        }
+    }
+
+    /// Returns the number of faces in this mesh.
+    pub fn num_faces(&self) -> u32 {
+        self.num_faces
+    }
+
+    /// Returns this mesh's faces, each a list of indices into `vertices`.
+    pub fn faces(&self) -> Vec<Face<'scene>> {
+        let len = self.num_faces as usize;
+        unsafe {
+            from_raw_parts(self.faces, len)
+                .iter()
+                .map(|face| Face::new(face as *const AiFace))
+                .collect()
+        }
+    }
+
+    /// Returns the index (into `Scene::materials`/`Scene::material`) of the material this mesh
+    /// uses.
+    pub fn material_index(&self) -> u32 {
+        self.material_index
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiMesh;
+
+    impl<'scene> Deref for super::Mesh<'scene> {
+        type Target = AiMesh;
+        fn deref<'a>(&'a self) -> &'a AiMesh { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait MeshInternal<'scene> {
+    fn new(raw_mesh: *mut AiMesh) -> Mesh<'scene> { Mesh(raw_mesh, PhantomData) }
+}
+
+impl<'scene> MeshInternal<'scene> for Mesh<'scene> {}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use ffi::*;
+
+/// A single decoded value from an `aiMetadataEntry`, tagged by its `aiMetadataType`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MetadataValue {
+    Bool(bool),
+    I32(i32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    Vec3(AiVector3D),
+}
+
+/// A typed, read-only view of an `aiMetadata` key/value map, as found on both `Scene`s and
+/// `Node`s. Keys are importer-defined (e.g. source format version, up-axis, unit scale, or
+/// FBX custom properties).
+///
+/// Borrowed from the owning `Scene`/`Node`, which is freed with `aiReleaseImport` on `Drop`; the
+/// `'scene` lifetime ties this view to that owner so it cannot outlive the memory it reads.
+pub struct Metadata<'scene>(*const AiMetadata, PhantomData<&'scene AiScene>);
+
+impl<'scene> Metadata<'scene> {
+    /// Returns the number of key/value entries in this map.
+    pub fn len(&self) -> usize {
+        unsafe { (*self.0).num_properties as usize }
+    }
+
+    /// Returns true if this map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the decoded `(key, value)` pairs, in the order Assimp stored them.
+    pub fn iter(&self) -> MetadataIter<'scene> {
+        MetadataIter { metadata: self.0, index: 0, len: self.len(), _scene: PhantomData }
+    }
+
+    /// Looks up a single entry by key. Linear in the number of entries, which importers keep small.
+    pub fn get(&self, key: &str) -> Option<MetadataValue> {
+        self.iter().find(|entry| entry.0 == key).map(|entry| entry.1)
+    }
+
+    /// Collects every entry into a `HashMap`.
+    pub fn to_map(&self) -> HashMap<String, MetadataValue> {
+        self.iter().collect()
+    }
+}
+
+/// Iterator over the decoded entries of a `Metadata` map. See `Metadata::iter`.
+pub struct MetadataIter<'scene> {
+    metadata: *const AiMetadata,
+    index: usize,
+    len: usize,
+    _scene: PhantomData<&'scene AiScene>,
+}
+
+impl<'scene> Iterator for MetadataIter<'scene> {
+    type Item = (String, MetadataValue);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let (key, value) = unsafe {
+            let metadata = &*self.metadata;
+            let key = (*metadata.keys.offset(self.index as isize)).as_str().to_owned();
+            let entry = &*metadata.values.offset(self.index as isize);
+            (key, decode_entry(entry))
+        };
+        self.index += 1;
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+unsafe fn decode_entry(entry: &AiMetadataEntry) -> MetadataValue {
+    match entry.data_type {
+        AiMetadataType::Bool => MetadataValue::Bool(*(entry.data as *const bool)),
+        AiMetadataType::Int32 => MetadataValue::I32(*(entry.data as *const i32)),
+        AiMetadataType::UInt64 => MetadataValue::U64(*(entry.data as *const u64)),
+        AiMetadataType::Float => MetadataValue::F32(*(entry.data as *const f32)),
+        AiMetadataType::Double => MetadataValue::F64(*(entry.data as *const f64)),
+        AiMetadataType::AiString => {
+            MetadataValue::String((*(entry.data as *const AiString)).as_str().to_owned())
+        }
+        AiMetadataType::AiVector3D => MetadataValue::Vec3(*(entry.data as *const AiVector3D)),
+    }
+}
+
+#[doc(hidden)]
+pub trait MetadataInternal<'scene> {
+    fn new(raw_metadata: *const AiMetadata) -> Metadata<'scene> { Metadata(raw_metadata, PhantomData) }
+}
+
+impl<'scene> MetadataInternal<'scene> for Metadata<'scene> {}
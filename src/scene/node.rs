@@ -0,0 +1,59 @@
+use std::marker::PhantomData;
+use std::slice::from_raw_parts;
+
+use ffi::*;
+
+use super::metadata::{Metadata, MetadataInternal};
+
+/// A node in a `Scene`'s hierarchy. Carries a `PhantomData<&'scene AiScene>` so a `Node` can
+/// never outlive the `Scene` it was read from, which is what frees the underlying memory on
+/// `Drop`.
+pub struct Node<'scene>(*mut AiNode, PhantomData<&'scene AiScene>);
+
+impl<'scene> Node<'scene> {
+    /// Returns this node's transformation relative to its parent.
+    pub fn transformation(&self) -> AiMatrix4x4 {
+        self.transformation
+    }
+
+    /// Returns the indices (into `Scene::meshes`/`Scene::mesh`) of the meshes this node
+    /// references.
+    pub fn mesh_indices(&self) -> &'scene [u32] {
+        let len = self.num_meshes as usize;
+        unsafe { from_raw_parts(self.meshes, len) }
+    }
+
+    /// Returns this node's direct children.
+    pub fn children(&self) -> Vec<Node<'scene>> {
+        let len = self.num_children as usize;
+        unsafe { from_raw_parts(self.children, len).iter().map(|&x| Node::new(x)).collect() }
+    }
+
+    /// Returns the metadata the importer attached to this node, or `None` if it didn't provide
+    /// any. Uses the same `Metadata` type as `Scene::metadata`.
+    pub fn metadata(&self) -> Option<Metadata<'scene>> {
+        if self.metadata.is_null() {
+            None
+        } else {
+            Some(Metadata::new(self.metadata))
+        }
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiNode;
+
+    impl<'scene> Deref for super::Node<'scene> {
+        type Target = AiNode;
+        fn deref<'a>(&'a self) -> &'a AiNode { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait NodeInternal<'scene> {
+    fn new(raw_node: *mut AiNode) -> Node<'scene> { Node(raw_node, PhantomData) }
+}
+
+impl<'scene> NodeInternal<'scene> for Node<'scene> {}
@@ -1,4 +1,6 @@
+use std::alloc::{self, Layout};
 use std::ops::Deref;
+use std::path::Path;
 use std::ptr;
 use std::slice::from_raw_parts;
 
@@ -7,17 +9,23 @@ use ffi::*;
 // Import all types (and internal traits for instantiating them)
 use super::animation::{Animation, AnimationInternal};
 use super::camera::{Camera, CameraInternal};
+use super::export::{self, Blob, ExportError, ExportFormatDesc, ExportInternal, ExportProperties, ExportPropertiesInternal};
 use super::face::{Face, FaceInternal};
 use super::light::{Light, LightInternal};
 use super::material::{Material, MaterialInternal};
 use super::mesh::{Mesh, MeshInternal};
+use super::metadata::{Metadata, MetadataInternal};
 use super::node::{Node, NodeInternal};
 use super::texture::{Texture, TextureInternal};
 
 /// The `Scene` type represents immutable scene data.
 pub struct Scene(*const AiScene);
 /// The `SceneMut` type represents mutable scene data.
-pub struct SceneMut(*mut AiScene);
+///
+/// Carries a `MutationState` alongside the raw pointer so that the `push_*`/`set_root_node`
+/// family can grow/replace Assimp-owned arrays without ever calling Rust's allocator on memory
+/// Assimp allocated, or handing Rust-allocated memory to `aiFreeScene`. See `MutationState`.
+pub struct SceneMut(*mut AiScene, MutationState);
 
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -52,8 +60,10 @@ impl Scene {
         self.flags.contains(AI_SCENE_FLAGS_TERRAIN)
     }
 
-    /// Returns the root node of the scene hierarchy
-    pub fn root_node(&self) -> Node {
+    /// Returns the root node of the scene hierarchy.
+    ///
+    /// Borrows from `self` so the returned `Node` cannot outlive the `Scene` that owns it.
+    pub fn root_node<'scene>(&'scene self) -> Node<'scene> {
         Node::new(self.root_node)
     }
 
@@ -62,16 +72,23 @@ impl Scene {
         self.num_meshes
     }
 
-    /// Returns a vector containing all of the meshes in the scene
-    pub fn meshes(&self) -> Vec<Mesh> {
+    /// Returns a vector containing all of the meshes in the scene.
+    pub fn meshes<'scene>(&'scene self) -> Vec<Mesh<'scene>> {
         let len = self.num_meshes as usize;
         unsafe { from_raw_parts(self.meshes, len).iter().map(|x| Mesh::new(*x)).collect() }
     }
 
+    /// Returns a lazy iterator over the meshes in the scene that constructs each `Mesh` on
+    /// demand, without the intermediate `Vec` allocation `meshes` makes.
+    pub fn iter_meshes<'scene>(&'scene self) -> MeshesIter<'scene> {
+        let len = self.num_meshes as usize;
+        MeshesIter { slice: unsafe { from_raw_parts(self.meshes, len) }, front: 0, back: len }
+    }
+
     /// Return an individual mesh from the scene.
     ///
     /// Panics if `id` is invalid.
-    pub fn mesh(&self, id: usize) -> Mesh {
+    pub fn mesh<'scene>(&'scene self, id: usize) -> Mesh<'scene> {
         assert!(id < self.num_meshes as usize);
         unsafe { Mesh::new(*(self.meshes.offset(id as isize))) }
     }
@@ -82,71 +99,583 @@ impl Scene {
     }
 
     /// Returns a vector containing all of the materials in the scene.
-    pub fn materials(&self) -> Vec<Material> {
+    pub fn materials<'scene>(&'scene self) -> Vec<Material<'scene>> {
         let len = self.num_materials as usize;
         unsafe { from_raw_parts(self.materials, len).iter().map(|x| Material::new(*x)).collect() }
     }
 
+    /// Returns a lazy iterator over the materials in the scene that constructs each `Material`
+    /// on demand, without the intermediate `Vec` allocation `materials` makes.
+    pub fn iter_materials<'scene>(&'scene self) -> MaterialsIter<'scene> {
+        let len = self.num_materials as usize;
+        MaterialsIter { slice: unsafe { from_raw_parts(self.materials, len) }, front: 0, back: len }
+    }
+
     /// Returns the number of animations in the scene.
     pub fn num_animations(&self) -> u32 {
         self.num_animations
     }
 
     /// Returns a vector containing all of the animations in the scene.
-    pub fn animations(&self) -> Vec<Animation> {
+    pub fn animations<'scene>(&'scene self) -> Vec<Animation<'scene>> {
         let len = self.num_animations as usize;
         unsafe { from_raw_parts(self.animations, len).iter().map(|x| Animation::new(*x)).collect() }
     }
 
+    /// Returns a lazy iterator over the animations in the scene that constructs each
+    /// `Animation` on demand, without the intermediate `Vec` allocation `animations` makes.
+    pub fn iter_animations<'scene>(&'scene self) -> AnimationsIter<'scene> {
+        let len = self.num_animations as usize;
+        AnimationsIter { slice: unsafe { from_raw_parts(self.animations, len) }, front: 0, back: len }
+    }
+
     /// Returns the number of animations in the scene.
     pub fn num_textures(&self) -> u32 {
         self.num_textures
     }
 
     /// Returns a vector containing all of the textures in the scene.
-    pub fn textures(&self) -> Vec<Texture> {
+    pub fn textures<'scene>(&'scene self) -> Vec<Texture<'scene>> {
         unsafe {
             let len = self.num_textures as usize;
             from_raw_parts(self.textures, len).iter().map(|x| Texture::new(*x)).collect()
         }
     }
 
+    /// Returns a lazy iterator over the textures in the scene that constructs each `Texture`
+    /// on demand, without the intermediate `Vec` allocation `textures` makes.
+    pub fn iter_textures<'scene>(&'scene self) -> TexturesIter<'scene> {
+        let len = self.num_textures as usize;
+        TexturesIter { slice: unsafe { from_raw_parts(self.textures, len) }, front: 0, back: len }
+    }
+
     /// Returns the number of lights in the scene.
     pub fn num_lights(&self) -> u32 {
         self.num_lights
     }
 
     /// Returns a vector containing all of the lights in the scene.
-    pub fn lights(&self) -> Vec<Light> {
+    pub fn lights<'scene>(&'scene self) -> Vec<Light<'scene>> {
         let len = self.num_lights as usize;
         unsafe { from_raw_parts(self.lights, len).iter().map(|x| Light::new(*x)).collect() }
     }
 
+    /// Returns a lazy iterator over the lights in the scene that constructs each `Light` on
+    /// demand, without the intermediate `Vec` allocation `lights` makes.
+    pub fn iter_lights<'scene>(&'scene self) -> LightsIter<'scene> {
+        let len = self.num_lights as usize;
+        LightsIter { slice: unsafe { from_raw_parts(self.lights, len) }, front: 0, back: len }
+    }
+
     /// Returns the number of cameras in the scene.
     pub fn num_cameras(&self) -> u32 {
         self.num_cameras
     }
 
-    /// Returns a vector containing all of the cameras in the scene
-    pub fn cameras(&self) -> Vec<Camera> {
+    /// Returns a vector containing all of the cameras in the scene.
+    pub fn cameras<'scene>(&'scene self) -> Vec<Camera<'scene>> {
         let len = self.num_cameras as usize;
         unsafe { from_raw_parts(self.cameras, len).iter().map(|x| Camera::new(*x)).collect() }
     }
+
+    /// Returns a lazy iterator over the cameras in the scene that constructs each `Camera` on
+    /// demand, without the intermediate `Vec` allocation `cameras` makes.
+    pub fn iter_cameras<'scene>(&'scene self) -> CamerasIter<'scene> {
+        let len = self.num_cameras as usize;
+        CamerasIter { slice: unsafe { from_raw_parts(self.cameras, len) }, front: 0, back: len }
+    }
+
+    /// Returns the scene-level metadata the importer attached (e.g. source format version,
+    /// up-axis, unit scale, or FBX custom properties), or `None` if the format/importer didn't
+    /// provide any.
+    pub fn metadata<'scene>(&'scene self) -> Option<Metadata<'scene>> {
+        if self.metadata.is_null() {
+            None
+        } else {
+            Some(Metadata::new(self.metadata))
+        }
+    }
+
+    /// Walks the node hierarchy and returns one `FlatMesh` per mesh-index reference, each paired
+    /// with its accumulated world transform. A node referencing the same mesh index more than
+    /// once, or reached through instancing, yields one `FlatMesh` per occurrence.
+    ///
+    /// Useful for renderer/ECS integration, where each drawable wants its final world matrix
+    /// without re-walking the hierarchy itself.
+    pub fn flatten(&self) -> Vec<FlatMesh> {
+        let mut flattened = Vec::new();
+        let mut stack = vec![(self.root_node(), IDENTITY_MATRIX4X4)];
+        while let Some((node, parent_global)) = stack.pop() {
+            let global = mat4_mul(&parent_global, &node.transformation());
+            for &mesh_index in node.mesh_indices() {
+                flattened.push(FlatMesh { mesh_index, global });
+            }
+            for child in node.children() {
+                stack.push((child, global));
+            }
+        }
+        flattened
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Flattened world-transform traversal
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A mesh index paired with its accumulated global (world) transform, as produced by
+/// `Scene::flatten`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlatMesh {
+    /// Index into `Scene::meshes`/`Scene::mesh`.
+    pub mesh_index: u32,
+    /// The mesh's world transform: the product of every `transformation()` from the root node
+    /// down to (and including) the node that references it.
+    pub global: AiMatrix4x4,
+}
+
+const IDENTITY_MATRIX4X4: AiMatrix4x4 = AiMatrix4x4 {
+    a1: 1.0, a2: 0.0, a3: 0.0, a4: 0.0,
+    b1: 0.0, b2: 1.0, b3: 0.0, b4: 0.0,
+    c1: 0.0, c2: 0.0, c3: 1.0, c4: 0.0,
+    d1: 0.0, d2: 0.0, d3: 0.0, d4: 1.0,
+};
+
+/// Multiplies two row-major `aiMatrix4x4`s the way Assimp lays them out: `a1..a4` is row 0,
+/// `b1..b4` is row 1, and so on.
+fn mat4_mul(lhs: &AiMatrix4x4, rhs: &AiMatrix4x4) -> AiMatrix4x4 {
+    AiMatrix4x4 {
+        a1: lhs.a1 * rhs.a1 + lhs.a2 * rhs.b1 + lhs.a3 * rhs.c1 + lhs.a4 * rhs.d1,
+        a2: lhs.a1 * rhs.a2 + lhs.a2 * rhs.b2 + lhs.a3 * rhs.c2 + lhs.a4 * rhs.d2,
+        a3: lhs.a1 * rhs.a3 + lhs.a2 * rhs.b3 + lhs.a3 * rhs.c3 + lhs.a4 * rhs.d3,
+        a4: lhs.a1 * rhs.a4 + lhs.a2 * rhs.b4 + lhs.a3 * rhs.c4 + lhs.a4 * rhs.d4,
+        b1: lhs.b1 * rhs.a1 + lhs.b2 * rhs.b1 + lhs.b3 * rhs.c1 + lhs.b4 * rhs.d1,
+        b2: lhs.b1 * rhs.a2 + lhs.b2 * rhs.b2 + lhs.b3 * rhs.c2 + lhs.b4 * rhs.d2,
+        b3: lhs.b1 * rhs.a3 + lhs.b2 * rhs.b3 + lhs.b3 * rhs.c3 + lhs.b4 * rhs.d3,
+        b4: lhs.b1 * rhs.a4 + lhs.b2 * rhs.b4 + lhs.b3 * rhs.c4 + lhs.b4 * rhs.d4,
+        c1: lhs.c1 * rhs.a1 + lhs.c2 * rhs.b1 + lhs.c3 * rhs.c1 + lhs.c4 * rhs.d1,
+        c2: lhs.c1 * rhs.a2 + lhs.c2 * rhs.b2 + lhs.c3 * rhs.c2 + lhs.c4 * rhs.d2,
+        c3: lhs.c1 * rhs.a3 + lhs.c2 * rhs.b3 + lhs.c3 * rhs.c3 + lhs.c4 * rhs.d3,
+        c4: lhs.c1 * rhs.a4 + lhs.c2 * rhs.b4 + lhs.c3 * rhs.c4 + lhs.c4 * rhs.d4,
+        d1: lhs.d1 * rhs.a1 + lhs.d2 * rhs.b1 + lhs.d3 * rhs.c1 + lhs.d4 * rhs.d1,
+        d2: lhs.d1 * rhs.a2 + lhs.d2 * rhs.b2 + lhs.d3 * rhs.c2 + lhs.d4 * rhs.d2,
+        d3: lhs.d1 * rhs.a3 + lhs.d2 * rhs.b3 + lhs.d3 * rhs.c3 + lhs.d4 * rhs.d3,
+        d4: lhs.d1 * rhs.a4 + lhs.d2 * rhs.b4 + lhs.d3 * rhs.c4 + lhs.d4 * rhs.d4,
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Zero-allocation iterator adapters for the `iter_*` accessors above
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! scene_slice_iter {
+    ($iter_name:ident, $raw_elem:ty, $item:ident) => {
+        /// See `Scene::iter_meshes`/`iter_materials`/etc. Constructs each item on demand instead
+        /// of eagerly collecting into a `Vec`.
+        pub struct $iter_name<'scene> {
+            slice: &'scene [$raw_elem],
+            front: usize,
+            back: usize,
+        }
+
+        impl<'scene> Iterator for $iter_name<'scene> {
+            type Item = $item<'scene>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front >= self.back {
+                    return None;
+                }
+                let item = $item::new(self.slice[self.front]);
+                self.front += 1;
+                Some(item)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+        }
+
+        impl<'scene> ExactSizeIterator for $iter_name<'scene> {}
+
+        impl<'scene> DoubleEndedIterator for $iter_name<'scene> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front >= self.back {
+                    return None;
+                }
+                self.back -= 1;
+                Some($item::new(self.slice[self.back]))
+            }
+        }
+    };
 }
 
+scene_slice_iter!(MeshesIter, *mut AiMesh, Mesh);
+scene_slice_iter!(MaterialsIter, *mut AiMaterial, Material);
+scene_slice_iter!(AnimationsIter, *mut AiAnimation, Animation);
+scene_slice_iter!(TexturesIter, *mut AiTexture, Texture);
+scene_slice_iter!(LightsIter, *mut AiLight, Light);
+scene_slice_iter!(CamerasIter, *mut AiCamera, Camera);
+
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // Mutable scene methods
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl SceneMut {
-    // TODO
+    /// Writes this scene to disk in the given format, applying `flags` as post-process steps
+    /// beforehand. `format_id` must be one of the ids returned by `SceneMut::export_formats`
+    /// (e.g. `"obj"`, `"gltf2"`, `"ply"`, `"fbx"`).
+    pub fn export(&self, format_id: &str, path: &Path, flags: PostProcessSteps) -> Result<(), ExportError> {
+        let format_id = export::to_cstring(format_id);
+        let path = export::to_cstring(&path.to_string_lossy());
+        let result = unsafe { aiExportScene(self.0, format_id.as_ptr(), path.as_ptr(), flags.bits()) };
+        if result == AI_SUCCESS {
+            Ok(())
+        } else {
+            Err(export::last_error_to_export_error())
+        }
+    }
+
+    /// Like `export`, but lets exporter-specific settings be passed through via `properties`
+    /// (e.g. glTF2's `"TEXT_PRETTY_PRINT"`). See `ExportProperties`.
+    pub fn export_with_properties(&self, format_id: &str, path: &Path, flags: PostProcessSteps, properties: &ExportProperties) -> Result<(), ExportError> {
+        let format_id = export::to_cstring(format_id);
+        let path = export::to_cstring(&path.to_string_lossy());
+        let result = unsafe {
+            aiExportSceneEx(self.0, format_id.as_ptr(), path.as_ptr(), properties.get_raw_ptr(), flags.bits())
+        };
+        if result == AI_SUCCESS {
+            Ok(())
+        } else {
+            Err(export::last_error_to_export_error())
+        }
+    }
+
+    /// Exports this scene into memory instead of writing it to disk, for callers that want to
+    /// stream or zip up the result without touching the filesystem.
+    pub fn export_to_blob(&self, format_id: &str, flags: PostProcessSteps) -> Result<Blob, ExportError> {
+        let format_id = export::to_cstring(format_id);
+        let blob = unsafe { aiExportSceneToBlob(self.0, format_id.as_ptr(), flags.bits()) };
+        if blob.is_null() {
+            Err(export::last_error_to_export_error())
+        } else {
+            Ok(Blob::new(blob))
+        }
+    }
+
+    /// Returns the number of file formats this Assimp build can export to.
+    pub fn export_format_count() -> usize {
+        export::export_format_count()
+    }
+
+    /// Returns the full list of file formats this Assimp build can export to.
+    pub fn export_formats() -> Vec<ExportFormatDesc> {
+        export::export_formats()
+    }
+
+    /// Appends a mesh to the scene, taking ownership of it. Returns the new mesh's index, for
+    /// use with `build_node`'s `mesh_indices`.
+    pub fn push_mesh(&mut self, mesh: AiMesh) -> u32 {
+        unsafe {
+            let raw = self.0;
+            let boxed = Box::into_raw(Box::new(mesh));
+            let index = (*raw).num_meshes;
+            (*raw).meshes = self.1.meshes.push((*raw).meshes, index, boxed);
+            (*raw).num_meshes += 1;
+            index
+        }
+    }
+
+    /// Appends a material to the scene, taking ownership of it. Returns the new material's index.
+    pub fn push_material(&mut self, material: AiMaterial) -> u32 {
+        unsafe {
+            let raw = self.0;
+            let boxed = Box::into_raw(Box::new(material));
+            let index = (*raw).num_materials;
+            (*raw).materials = self.1.materials.push((*raw).materials, index, boxed);
+            (*raw).num_materials += 1;
+            index
+        }
+    }
+
+    /// Appends a light to the scene, taking ownership of it. Returns the new light's index.
+    pub fn push_light(&mut self, light: AiLight) -> u32 {
+        unsafe {
+            let raw = self.0;
+            let boxed = Box::into_raw(Box::new(light));
+            let index = (*raw).num_lights;
+            (*raw).lights = self.1.lights.push((*raw).lights, index, boxed);
+            (*raw).num_lights += 1;
+            index
+        }
+    }
+
+    /// Appends a camera to the scene, taking ownership of it. Returns the new camera's index.
+    pub fn push_camera(&mut self, camera: AiCamera) -> u32 {
+        unsafe {
+            let raw = self.0;
+            let boxed = Box::into_raw(Box::new(camera));
+            let index = (*raw).num_cameras;
+            (*raw).cameras = self.1.cameras.push((*raw).cameras, index, boxed);
+            (*raw).num_cameras += 1;
+            index
+        }
+    }
+
+    /// Appends a texture to the scene, taking ownership of it. Returns the new texture's index.
+    pub fn push_texture(&mut self, texture: AiTexture) -> u32 {
+        unsafe {
+            let raw = self.0;
+            let boxed = Box::into_raw(Box::new(texture));
+            let index = (*raw).num_textures;
+            (*raw).textures = self.1.textures.push((*raw).textures, index, boxed);
+            (*raw).num_textures += 1;
+            index
+        }
+    }
+
+    /// Replaces the scene's root node, taking ownership of the new one. The previously installed
+    /// root node (whether it's the one Assimp originally imported, or one installed by an
+    /// earlier `set_root_node` call) is freed correctly: see `MutationState`. Build the node
+    /// (and its children) with `SceneMut::build_node` and `SceneMut::attach_child` first.
+    ///
+    /// # Safety
+    /// `root`, and every node transitively reachable through its `children`, must be Rust
+    /// allocated — built via `build_node`/`attach_child` — never a node copied or read from an
+    /// existing `Scene`'s tree. Replacing (or, on `Drop`, freeing) the currently installed root
+    /// frees that whole Rust-owned subtree with Rust's allocator; an Assimp-owned node or
+    /// subtree reached this way is undefined behaviour, for the same reason `attach_child` is
+    /// unsafe.
+    pub unsafe fn set_root_node(&mut self, root: AiNode) {
+        let raw = self.0;
+        let boxed = Box::into_raw(Box::new(root));
+        if self.1.root_node_replaced {
+            // The node currently installed is one *we* allocated (possibly with its own
+            // Rust-allocated subtree) on a previous call, so it's ours to free.
+            free_node_tree((*raw).root_node);
+        } else {
+            // The node currently installed is the one Assimp imported/copied the scene
+            // with; remember it so `Drop` can hand it back to `aiFreeScene` untouched.
+            self.1.original_root_node = (*raw).root_node;
+            self.1.root_node_replaced = true;
+        }
+        (*raw).root_node = boxed;
+    }
+
+    /// Builds a new, parentless node with the given name, local transform, and mesh-index
+    /// references, ready to be adopted as a root (`set_root_node`) or a child (`attach_child`).
+    pub fn build_node(name: &str, transformation: AiMatrix4x4, mesh_indices: &[u32]) -> AiNode {
+        let boxed_meshes = mesh_indices.to_vec().into_boxed_slice();
+        AiNode {
+            name: AiString::from(name),
+            transformation: transformation,
+            parent: ptr::null_mut(),
+            num_children: 0,
+            children: ptr::null_mut(),
+            num_meshes: boxed_meshes.len() as u32,
+            meshes: Box::into_raw(boxed_meshes) as *mut u32,
+            metadata: ptr::null_mut(),
+        }
+    }
+
+    /// Attaches `child` as an additional child of `parent`, taking ownership of it and pointing
+    /// `child`'s parent back at `parent`.
+    ///
+    /// # Safety
+    /// `parent` must be a node Rust allocated — one returned by `build_node`, or previously
+    /// passed as the `child` of `attach_child` or the `root` of `set_root_node` — never a
+    /// pointer into a node Assimp imported (e.g. anything reachable from `Scene::root_node`).
+    /// This grows `parent`'s children array with Rust's allocator on the assumption that it
+    /// either is null or was itself allocated the same way; passing an Assimp-owned node
+    /// violates that and is undefined behaviour.
+    pub unsafe fn attach_child(parent: *mut AiNode, child: AiNode) {
+        let boxed_child = Box::into_raw(Box::new(child));
+        (*boxed_child).parent = parent;
+        (*parent).children = push_into_child_array((*parent).children, (*parent).num_children, boxed_child);
+        (*parent).num_children += 1;
+    }
+
+    /// Clears `AI_SCENE_FLAGS_INCOMPLETE` once a valid root node and mesh set exists. Several
+    /// post-process steps (and some exporters) refuse to run while this flag is set. If the
+    /// scene has no root node or no meshes yet, the flag is left as it is.
+    pub fn mark_complete(&mut self) {
+        unsafe {
+            let raw = self.get_raw_ptr_mut();
+            if !(*raw).root_node.is_null() && (*raw).num_meshes > 0 {
+                (*raw).flags.remove(AI_SCENE_FLAGS_INCOMPLETE);
+            }
+        }
+    }
+}
+
+/// Grows a freshly built (and therefore always Rust-allocated) node's `mChildren` array by one
+/// element and writes `new_child` into the new slot. Only valid for the node trees `build_node`/
+/// `attach_child` build themselves — see `SceneMut::attach_child`'s safety section.
+unsafe fn push_into_child_array(array: *mut *mut AiNode, len: u32, new_child: *mut AiNode) -> *mut *mut AiNode {
+    let old_len = len as usize;
+    let new_len = old_len + 1;
+    let new_layout = Layout::array::<*mut AiNode>(new_len).expect("node children array size overflow");
+    let new_array = if array.is_null() {
+        alloc::alloc(new_layout) as *mut *mut AiNode
+    } else {
+        let old_layout = Layout::array::<*mut AiNode>(old_len).expect("node children array size overflow");
+        alloc::realloc(array as *mut u8, old_layout, new_layout.size()) as *mut *mut AiNode
+    };
+    assert!(!new_array.is_null(), "allocation failure while growing a node's children array");
+    ptr::write(new_array.add(old_len), new_child);
+    new_array
+}
+
+/// Recursively frees a Rust-allocated node tree built by `SceneMut::build_node`/`attach_child`:
+/// every descendant reachable through `children`, the `children` spine array itself (allocated
+/// by `push_into_child_array`, so freed with `alloc::dealloc` rather than `Box::from_raw`), the
+/// `meshes` slice `build_node` boxed, and finally the node itself. `node` (and everything below
+/// it) must be Rust-allocated — see `SceneMut::set_root_node`'s safety section.
+unsafe fn free_node_tree(node: *mut AiNode) {
+    let num_children = (*node).num_children as usize;
+    if !(*node).children.is_null() {
+        for i in 0..num_children {
+            free_node_tree(*(*node).children.add(i));
+        }
+        let layout = Layout::array::<*mut AiNode>(num_children).expect("node children array size overflow");
+        alloc::dealloc((*node).children as *mut u8, layout);
+    }
+    if !(*node).meshes.is_null() {
+        let num_meshes = (*node).num_meshes as usize;
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut((*node).meshes, num_meshes)));
+    }
+    drop(Box::from_raw(node));
+}
+
+/// Tracks, per `mNumX`/`mX` array on a `SceneMut` (plus its root node), whether `push_*`/
+/// `set_root_node` has replaced Assimp's original allocation with a Rust-allocated one.
+///
+/// This exists because `SceneMut` can only be obtained from an already-populated, Assimp-owned
+/// `aiScene` (via `aiCopyScene` or the internal raw-pointer constructor): its arrays and root
+/// node are allocated with Assimp's (C++) allocator, not Rust's. Rust's allocator must never
+/// `realloc`/`dealloc` a block it didn't `alloc`, and `aiFreeScene` (which `delete[]`s/`delete`s
+/// everything reachable from the scene on `Drop`) must never be handed a Rust-allocated block
+/// either. `MutationState` keeps the two worlds apart: growth always copies into a fresh,
+/// Rust-owned allocation (never `realloc`s Assimp's), and `Drop` restores each array/the root
+/// node to exactly what Assimp originally handed over before calling `aiFreeScene`, freeing
+/// everything Rust allocated itself first.
+struct MutationState {
+    meshes: ArrayState<AiMesh>,
+    materials: ArrayState<AiMaterial>,
+    lights: ArrayState<AiLight>,
+    cameras: ArrayState<AiCamera>,
+    textures: ArrayState<AiTexture>,
+    original_root_node: *mut AiNode,
+    root_node_replaced: bool,
+}
+
+impl MutationState {
+    /// Snapshots `raw`'s current arrays/root node as the "original, Assimp-owned" state.
+    unsafe fn capture(raw: *mut AiScene) -> MutationState {
+        MutationState {
+            meshes: ArrayState::capture((*raw).meshes, (*raw).num_meshes),
+            materials: ArrayState::capture((*raw).materials, (*raw).num_materials),
+            lights: ArrayState::capture((*raw).lights, (*raw).num_lights),
+            cameras: ArrayState::capture((*raw).cameras, (*raw).num_cameras),
+            textures: ArrayState::capture((*raw).textures, (*raw).num_textures),
+            original_root_node: (*raw).root_node,
+            root_node_replaced: false,
+        }
+    }
+
+    /// Frees everything Rust allocated on top of `raw`'s original arrays/root node, and restores
+    /// `raw` to point at exactly what Assimp originally gave us, so the `aiFreeScene` call that
+    /// follows only ever frees memory Assimp itself allocated.
+    unsafe fn reclaim(&self, raw: *mut AiScene) {
+        let (meshes, num_meshes) = self.meshes.reclaim((*raw).meshes, (*raw).num_meshes);
+        (*raw).meshes = meshes;
+        (*raw).num_meshes = num_meshes;
+
+        let (materials, num_materials) = self.materials.reclaim((*raw).materials, (*raw).num_materials);
+        (*raw).materials = materials;
+        (*raw).num_materials = num_materials;
+
+        let (lights, num_lights) = self.lights.reclaim((*raw).lights, (*raw).num_lights);
+        (*raw).lights = lights;
+        (*raw).num_lights = num_lights;
+
+        let (cameras, num_cameras) = self.cameras.reclaim((*raw).cameras, (*raw).num_cameras);
+        (*raw).cameras = cameras;
+        (*raw).num_cameras = num_cameras;
+
+        let (textures, num_textures) = self.textures.reclaim((*raw).textures, (*raw).num_textures);
+        (*raw).textures = textures;
+        (*raw).num_textures = num_textures;
+
+        if self.root_node_replaced {
+            free_node_tree((*raw).root_node);
+            (*raw).root_node = self.original_root_node;
+        }
+    }
+}
+
+/// Per-array half of `MutationState`: the original Assimp-owned `(array, len)` this scene
+/// started with, and whether `push` has since replaced it with a Rust-owned one.
+struct ArrayState<T> {
+    original_array: *mut *mut T,
+    original_len: u32,
+    grown: bool,
+}
+
+impl<T> ArrayState<T> {
+    fn capture(array: *mut *mut T, len: u32) -> ArrayState<T> {
+        ArrayState { original_array: array, original_len: len, grown: false }
+    }
+
+    /// Grows the array by one element. The first call copies `current_array`'s elements into a
+    /// fresh, Rust-owned allocation rather than `realloc`ing it (it may still be Assimp's
+    /// original, foreign allocation); every call after that `realloc`s the Rust-owned
+    /// allocation `push` itself made.
+    unsafe fn push(&mut self, current_array: *mut *mut T, current_len: u32, new_elem: *mut T) -> *mut *mut T {
+        let new_len = current_len as usize + 1;
+        let new_layout = Layout::array::<*mut T>(new_len).expect("scene array size overflow");
+        let new_array = if self.grown {
+            let old_layout = Layout::array::<*mut T>(current_len as usize).expect("scene array size overflow");
+            alloc::realloc(current_array as *mut u8, old_layout, new_layout.size()) as *mut *mut T
+        } else {
+            let fresh = alloc::alloc(new_layout) as *mut *mut T;
+            if !current_array.is_null() && current_len > 0 {
+                ptr::copy_nonoverlapping(current_array, fresh, current_len as usize);
+            }
+            fresh
+        };
+        assert!(!new_array.is_null(), "allocation failure while growing a scene array");
+        ptr::write(new_array.add(current_len as usize), new_elem);
+        self.grown = true;
+        new_array
+    }
+
+    /// If `push` ever grew this array, frees the elements it appended and the Rust-owned spine
+    /// array itself, then reports the original Assimp-owned `(array, len)` to restore. Otherwise
+    /// just reports `current_array`/`current_len` back unchanged (still Assimp's own).
+    unsafe fn reclaim(&self, current_array: *mut *mut T, current_len: u32) -> (*mut *mut T, u32) {
+        if !self.grown {
+            return (current_array, current_len);
+        }
+        for i in (self.original_len as usize)..(current_len as usize) {
+            drop(Box::from_raw(*current_array.add(i)));
+        }
+        let layout = Layout::array::<*mut T>(current_len as usize).expect("scene array size overflow");
+        alloc::dealloc(current_array as *mut u8, layout);
+        (self.original_array, self.original_len)
+    }
 }
 
 impl Deref for SceneMut {
     type Target = Scene;
     fn deref<'a>(&'a self) -> &'a Scene {
-        unsafe { ::std::mem::transmute(self) }
+        // `Scene` is a single-field tuple struct around a pointer, so reinterpreting the
+        // address of our own pointer field (not `self` as a whole, which now also carries a
+        // `MutationState`) as a `&Scene` is sound regardless of where that field ends up in
+        // `SceneMut`'s layout.
+        unsafe { &*(&self.0 as *const *mut AiScene as *const Scene) }
     }
 }
 
@@ -160,7 +689,8 @@ impl From<Scene> for SceneMut {
     fn from(scene: Scene) -> SceneMut {
         let mut new_scene = ptr::null_mut();
         unsafe { aiCopyScene(scene.0, &mut new_scene) };
-        SceneMut(new_scene)
+        let state = unsafe { MutationState::capture(new_scene) };
+        SceneMut(new_scene, state)
     }
 }
 
@@ -176,7 +706,12 @@ impl Drop for Scene {
 // Scenes returned by aiCopyScene must be freed with aiFreeScene.
 impl Drop for SceneMut {
     fn drop(&mut self) {
-        unsafe { aiFreeScene(self.0); }
+        unsafe {
+            // Undo any push_*/set_root_node replacements before aiFreeScene walks the scene,
+            // so it only ever frees memory Assimp itself allocated. See `MutationState`.
+            self.1.reclaim(self.0);
+            aiFreeScene(self.0);
+        }
     }
 }
 
@@ -204,7 +739,10 @@ pub trait SceneInternal {
 
 #[doc(hidden)]
 pub trait SceneMutInternal: SceneInternal {
-    fn new(raw_scene: *mut AiScene) -> SceneMut { SceneMut(raw_scene) }
+    fn new(raw_scene: *mut AiScene) -> SceneMut {
+        let state = unsafe { MutationState::capture(raw_scene) };
+        SceneMut(raw_scene, state)
+    }
     fn get_raw_ptr_mut(&mut self) -> *mut AiScene;
 }
 
@@ -219,3 +757,94 @@ impl SceneInternal for SceneMut {
 impl SceneMutInternal for SceneMut {
     fn get_raw_ptr_mut(&mut self) -> *mut AiScene { self.0 }
 }
+
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mat4_mul_identity_is_a_no_op() {
+        let m = AiMatrix4x4 {
+            a1: 2.0, a2: 0.0, a3: 0.0, a4: 5.0,
+            b1: 0.0, b2: 3.0, b3: 0.0, b4: 6.0,
+            c1: 0.0, c2: 0.0, c3: 4.0, c4: 7.0,
+            d1: 0.0, d2: 0.0, d3: 0.0, d4: 1.0,
+        };
+        assert_eq!(mat4_mul(&IDENTITY_MATRIX4X4, &m), m);
+        assert_eq!(mat4_mul(&m, &IDENTITY_MATRIX4X4), m);
+    }
+
+    #[test]
+    fn mat4_mul_composes_scale_then_translate() {
+        let scale = AiMatrix4x4 {
+            a1: 2.0, a2: 0.0, a3: 0.0, a4: 0.0,
+            b1: 0.0, b2: 2.0, b3: 0.0, b4: 0.0,
+            c1: 0.0, c2: 0.0, c3: 2.0, c4: 0.0,
+            d1: 0.0, d2: 0.0, d3: 0.0, d4: 1.0,
+        };
+        let translate = AiMatrix4x4 {
+            a1: 1.0, a2: 0.0, a3: 0.0, a4: 1.0,
+            b1: 0.0, b2: 1.0, b3: 0.0, b4: 2.0,
+            c1: 0.0, c2: 0.0, c3: 1.0, c4: 3.0,
+            d1: 0.0, d2: 0.0, d3: 0.0, d4: 1.0,
+        };
+        // node.transformation() composition order is parent_global * node.transformation(), so
+        // a child's own "scale" transform combined with a parent "translate" transform should
+        // scale a point first, then translate it.
+        let global = mat4_mul(&translate, &scale);
+        let x = global.a1 * 1.0 + global.a2 * 1.0 + global.a3 * 1.0 + global.a4;
+        let y = global.b1 * 1.0 + global.b2 * 1.0 + global.b3 * 1.0 + global.b4;
+        let z = global.c1 * 1.0 + global.c2 * 1.0 + global.c3 * 1.0 + global.c4;
+        assert_eq!((x, y, z), (3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn array_state_push_grows_without_touching_the_original_array() {
+        unsafe {
+            let original: Box<[*mut i32]> = vec![Box::into_raw(Box::new(1)), Box::into_raw(Box::new(2))].into_boxed_slice();
+            let original_ptr = Box::into_raw(original) as *mut *mut i32;
+            let mut state = ArrayState::capture(original_ptr, 2);
+
+            let appended = Box::into_raw(Box::new(3));
+            let grown = state.push(original_ptr, 2, appended);
+
+            // The original two elements were copied, not mutated in place.
+            assert_eq!(*original_ptr.add(0), *grown.add(0));
+            assert_eq!(*original_ptr.add(1), *grown.add(1));
+            assert_eq!(*grown.add(2), appended);
+
+            let (restored_ptr, restored_len) = state.reclaim(grown, 3);
+            assert_eq!(restored_ptr, original_ptr);
+            assert_eq!(restored_len, 2);
+
+            // Clean up the untouched "original" allocation ourselves, simulating aiFreeScene.
+            let original = Box::from_raw(ptr::slice_from_raw_parts_mut(restored_ptr, restored_len as usize));
+            for &elem in original.iter() {
+                drop(Box::from_raw(elem));
+            }
+        }
+    }
+
+    #[test]
+    fn array_state_reclaim_is_a_no_op_before_any_push() {
+        unsafe {
+            let original: Box<[*mut i32]> = vec![Box::into_raw(Box::new(1))].into_boxed_slice();
+            let original_ptr = Box::into_raw(original) as *mut *mut i32;
+            let state = ArrayState::capture(original_ptr, 1);
+
+            let (restored_ptr, restored_len) = state.reclaim(original_ptr, 1);
+            assert_eq!(restored_ptr, original_ptr);
+            assert_eq!(restored_len, 1);
+
+            let original = Box::from_raw(ptr::slice_from_raw_parts_mut(restored_ptr, restored_len as usize));
+            for &elem in original.iter() {
+                drop(Box::from_raw(elem));
+            }
+        }
+    }
+}
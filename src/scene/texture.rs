@@ -0,0 +1,70 @@
+use std::marker::PhantomData;
+use std::slice::from_raw_parts;
+
+use ffi::*;
+
+/// A texture within a `Scene`. Carries a `PhantomData<&'scene AiScene>` so a `Texture` can
+/// never outlive the `Scene` it was read from, which is what frees the underlying memory on
+/// `Drop`.
+pub struct Texture<'scene>(*mut AiTexture, PhantomData<&'scene AiScene>);
+
+impl<'scene> Texture<'scene> {
+    /// Returns the texture's width. For a compressed texture (see `is_compressed`), this is the
+    /// size of the encoded data in bytes instead.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the texture's height, or `0` if the texture is compressed (see `is_compressed`).
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns true if this texture holds the raw bytes of a compressed image format (e.g. PNG,
+    /// JPEG — see `format_hint`) rather than decoded, uncompressed texel data.
+    pub fn is_compressed(&self) -> bool {
+        self.height == 0
+    }
+
+    /// Returns a hint at this texture's format (e.g. `"png"`, `"jpg"`, `"dds"`), trimmed of
+    /// trailing NUL padding.
+    pub fn format_hint(&self) -> String {
+        let bytes = &self.format_hint;
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        bytes[..len].iter().map(|&b| b as u8 as char).collect()
+    }
+
+    /// Returns the original filename this texture was embedded from, if the importer recorded one.
+    pub fn filename(&self) -> String {
+        self.filename.as_str().to_owned()
+    }
+
+    /// Returns this texture's raw data: the encoded file bytes if `is_compressed`, or
+    /// `width * height` BGRA texels otherwise.
+    pub fn raw_bytes(&self) -> &'scene [u8] {
+        let len = if self.is_compressed() {
+            self.width as usize
+        } else {
+            self.width as usize * self.height as usize * 4
+        };
+        unsafe { from_raw_parts(self.data as *const u8, len) }
+    }
+}
+
+#[doc(hidden)]
+mod private {
+    use std::ops::Deref;
+    use ffi::AiTexture;
+
+    impl<'scene> Deref for super::Texture<'scene> {
+        type Target = AiTexture;
+        fn deref<'a>(&'a self) -> &'a AiTexture { unsafe { &*self.0 } }
+    }
+}
+
+#[doc(hidden)]
+pub trait TextureInternal<'scene> {
+    fn new(raw_texture: *mut AiTexture) -> Texture<'scene> { Texture(raw_texture, PhantomData) }
+}
+
+impl<'scene> TextureInternal<'scene> for Texture<'scene> {}